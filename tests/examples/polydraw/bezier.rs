@@ -0,0 +1,205 @@
+use geom::point::Point;
+use num::NumberOps;
+
+use super::scene::Scene;
+use super::line_edges::push_line_edge;
+
+
+pub struct CubicBezier {
+   pub p0: Point,
+   pub p1: Point,
+   pub p2: Point,
+   pub p3: Point,
+}
+
+pub struct QuadBezier {
+   pub p0: Point,
+   pub p1: Point,
+   pub p2: Point,
+}
+
+
+impl QuadBezier {
+   #[inline]
+   pub fn elevate(&self) -> CubicBezier {
+      let c1 = Point::new(
+         self.p0.x + 2 * (self.p1.x - self.p0.x) / 3,
+         self.p0.y + 2 * (self.p1.y - self.p0.y) / 3,
+      );
+      let c2 = Point::new(
+         self.p2.x + 2 * (self.p1.x - self.p2.x) / 3,
+         self.p2.y + 2 * (self.p1.y - self.p2.y) / 3,
+      );
+
+      CubicBezier {
+         p0: self.p0,
+         p1: c1,
+         p2: c2,
+         p3: self.p2,
+      }
+   }
+}
+
+
+const MAX_FLATTEN_DEPTH: u32 = 24;
+
+
+pub fn flatten_cubic(curve: &CubicBezier, flattening_tolerance: i64, out: &mut Vec<Point>) {
+   out.push(curve.p0);
+   flatten_cubic_rec(curve, flattening_tolerance, MAX_FLATTEN_DEPTH, out);
+   out.push(curve.p3);
+}
+
+pub fn flatten_quad(curve: &QuadBezier, flattening_tolerance: i64, out: &mut Vec<Point>) {
+   flatten_cubic(&curve.elevate(), flattening_tolerance, out);
+}
+
+
+pub struct SceneBezierBuilder<'a> {
+   scene: &'a mut Scene,
+   flattening_tolerance: i64,
+}
+
+impl<'a> SceneBezierBuilder<'a> {
+   #[inline]
+   pub fn new(scene: &'a mut Scene, flattening_tolerance: i64) -> Self {
+      SceneBezierBuilder {
+         scene: scene,
+         flattening_tolerance: flattening_tolerance,
+      }
+   }
+
+   pub fn add_cubic_bezier(&mut self, curve: &CubicBezier) {
+      let mut flattened = Vec::new();
+      flatten_cubic(curve, self.flattening_tolerance, &mut flattened);
+
+      add_polyline_edges(self.scene, &flattened);
+   }
+
+   pub fn add_quad_bezier(&mut self, curve: &QuadBezier) {
+      self.add_cubic_bezier(&curve.elevate());
+   }
+}
+
+
+fn add_polyline_edges(scene: &mut Scene, points: &[Point]) {
+   for i in 0..points.len() - 1 {
+      push_line_edge(scene, points[i], points[i + 1]);
+   }
+}
+
+
+fn flatten_cubic_rec(curve: &CubicBezier, flattening_tolerance: i64, depth: u32, out: &mut Vec<Point>) {
+   if depth == 0 || is_flat_enough(curve, flattening_tolerance) {
+      return;
+   }
+
+   let (left, right) = split_cubic(curve);
+
+   flatten_cubic_rec(&left, flattening_tolerance, depth - 1, out);
+   out.push(left.p3);
+   flatten_cubic_rec(&right, flattening_tolerance, depth - 1, out);
+}
+
+
+fn is_flat_enough(curve: &CubicBezier, flattening_tolerance: i64) -> bool {
+   let d1 = perpendicular_distance(curve.p1, curve.p0, curve.p3);
+   let d2 = perpendicular_distance(curve.p2, curve.p0, curve.p3);
+
+   max(d1, d2) <= flattening_tolerance
+}
+
+fn perpendicular_distance(p: Point, a: Point, b: Point) -> i64 {
+   let dx = b.x - a.x;
+   let dy = b.y - a.y;
+
+   let len_sq = dx * dx + dy * dy;
+   if len_sq == 0 {
+      let ex = p.x - a.x;
+      let ey = p.y - a.y;
+      return (ex * ex + ey * ey).sqrt();
+   }
+
+   let cross = (p.x - a.x) * dy - (p.y - a.y) * dx;
+
+   cross.abs() / len_sq.sqrt()
+}
+
+fn split_cubic(curve: &CubicBezier) -> (CubicBezier, CubicBezier) {
+   let p01 = midpoint(curve.p0, curve.p1);
+   let p12 = midpoint(curve.p1, curve.p2);
+   let p23 = midpoint(curve.p2, curve.p3);
+
+   let p012 = midpoint(p01, p12);
+   let p123 = midpoint(p12, p23);
+
+   let p0123 = midpoint(p012, p123);
+
+   let left = CubicBezier {
+      p0: curve.p0,
+      p1: p01,
+      p2: p012,
+      p3: p0123,
+   };
+
+   let right = CubicBezier {
+      p0: p0123,
+      p1: p123,
+      p2: p23,
+      p3: curve.p3,
+   };
+
+   (left, right)
+}
+
+#[inline]
+fn midpoint(a: Point, b: Point) -> Point {
+   Point::new((a.x + b.x) / 2, (a.y + b.y) / 2)
+}
+
+#[inline]
+fn max(a: i64, b: i64) -> i64 {
+   if a > b { a } else { b }
+}
+
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn flatten_straight_curve_needs_no_subdivision() {
+      let curve = CubicBezier {
+         p0: Point::new(0, 0),
+         p1: Point::new(10, 0),
+         p2: Point::new(20, 0),
+         p3: Point::new(30, 0),
+      };
+
+      let mut out = Vec::new();
+      flatten_cubic(&curve, 1, &mut out);
+
+      assert_eq!(out.len(), 2);
+      assert_eq!(out[0].x, 0);
+      assert_eq!(out[0].y, 0);
+      assert_eq!(out[1].x, 30);
+      assert_eq!(out[1].y, 0);
+   }
+
+   #[test]
+   fn flatten_respects_max_depth_on_pathological_input() {
+      let curve = CubicBezier {
+         p0: Point::new(0, 0),
+         p1: Point::new(1, 1000),
+         p2: Point::new(-1, -1000),
+         p3: Point::new(0, 0),
+      };
+
+      let depth = 4;
+      let mut out = vec![curve.p0];
+      flatten_cubic_rec(&curve, 0, depth, &mut out);
+      out.push(curve.p3);
+
+      assert!(out.len() <= (1usize << depth) + 1);
+   }
+}
@@ -0,0 +1,102 @@
+use geom::point::Point;
+
+use super::scene::Scene;
+use super::line_edges::push_line_edge;
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClipRect {
+   pub min_x: i64,
+   pub min_y: i64,
+   pub max_x: i64,
+   pub max_y: i64,
+}
+
+
+pub fn clip_polygon(points: &[Point], clip: &ClipRect) -> Vec<Point> {
+   let mut result = clip_edge(points, |p| p.x >= clip.min_x, |a, b| {
+      intersect_x(a, b, clip.min_x)
+   });
+
+   result = clip_edge(&result, |p| p.y >= clip.min_y, |a, b| {
+      intersect_y(a, b, clip.min_y)
+   });
+
+   result = clip_edge(&result, |p| p.x <= clip.max_x, |a, b| {
+      intersect_x(a, b, clip.max_x)
+   });
+
+   result = clip_edge(&result, |p| p.y <= clip.max_y, |a, b| {
+      intersect_y(a, b, clip.max_y)
+   });
+
+   result
+}
+
+
+pub fn add_clipped_polygon(scene: &mut Scene, points: &[Point], clip: &ClipRect) {
+   let clipped = clip_polygon(points, clip);
+   add_closed_polygon_edges(scene, &clipped);
+}
+
+
+fn add_closed_polygon_edges(scene: &mut Scene, points: &[Point]) {
+   if points.len() < 2 {
+      return;
+   }
+
+   for i in 0..points.len() {
+      push_line_edge(scene, points[i], points[(i + 1) % points.len()]);
+   }
+}
+
+
+fn clip_edge<F, G>(points: &[Point], inside: F, intersect: G) -> Vec<Point>
+   where F: Fn(Point) -> bool, G: Fn(Point, Point) -> Point
+{
+   if points.is_empty() {
+      return Vec::new();
+   }
+
+   let mut result = Vec::with_capacity(points.len() + 1);
+
+   let mut prev = points[points.len() - 1];
+   let mut prev_inside = inside(prev);
+
+   for &curr in points {
+      let curr_inside = inside(curr);
+
+      if curr_inside {
+         if !prev_inside {
+            result.push(intersect(prev, curr));
+         }
+         result.push(curr);
+      } else if prev_inside {
+         result.push(intersect(prev, curr));
+      }
+
+      prev = curr;
+      prev_inside = curr_inside;
+   }
+
+   result
+}
+
+
+fn intersect_x(from: Point, to: Point, boundary: i64) -> Point {
+   let dx = to.x - from.x;
+   let dy = to.y - from.y;
+
+   let y = from.y + dy * (boundary - from.x) / dx;
+
+   Point::new(boundary, y)
+}
+
+fn intersect_y(from: Point, to: Point, boundary: i64) -> Point {
+   let dx = to.x - from.x;
+   let dy = to.y - from.y;
+
+   let x = from.x + dx * (boundary - from.y) / dy;
+
+   Point::new(x, boundary)
+}
@@ -0,0 +1,121 @@
+use geom::point::Point;
+
+use super::scene::Scene;
+
+
+const SINGULAR_EPSILON: f64 = 1e-9;
+
+
+pub struct Homography {
+   m: [f64; 9],
+}
+
+
+impl Homography {
+   pub fn from_quads(src: [Point; 4], dst: [Point; 4]) -> Option<Homography> {
+      let m = match solve_homography(src, dst) {
+         Some(m) => m,
+         None => return None,
+      };
+
+      Some(Homography { m: m })
+   }
+
+   #[inline]
+   pub fn transform(&self, p: Point, div_per_pixel: i64) -> Point {
+      let x = p.x as f64 / div_per_pixel as f64;
+      let y = p.y as f64 / div_per_pixel as f64;
+
+      let m = &self.m;
+
+      let xp = m[0] * x + m[1] * y + m[2];
+      let yp = m[3] * x + m[4] * y + m[5];
+      let wp = m[6] * x + m[7] * y + m[8];
+
+      let x_out = (xp / wp * div_per_pixel as f64).round() as i64;
+      let y_out = (yp / wp * div_per_pixel as f64).round() as i64;
+
+      Point::new(x_out, y_out)
+   }
+
+   pub fn transform_points(&self, points: &[Point], div_per_pixel: i64) -> Vec<Point> {
+      points.iter().map(|&p| self.transform(p, div_per_pixel)).collect()
+   }
+
+   pub fn transform_scene(&self, scene: &mut Scene, div_per_pixel: i64) {
+      debug_assert!(scene.circles.is_empty());
+      debug_assert!(scene.ellipses.is_empty());
+
+      for point in scene.points.iter_mut() {
+         *point = self.transform(*point, div_per_pixel);
+      }
+   }
+}
+
+
+fn solve_homography(src: [Point; 4], dst: [Point; 4]) -> Option<[f64; 9]> {
+   let mut a = [[0f64; 9]; 8];
+   let mut b = [0f64; 8];
+
+   for i in 0..4 {
+      let (x, y) = (src[i].x as f64, src[i].y as f64);
+      let (xp, yp) = (dst[i].x as f64, dst[i].y as f64);
+
+      a[2 * i] = [x, y, 1.0, 0.0, 0.0, 0.0, -x * xp, -y * xp, 0.0];
+      b[2 * i] = xp;
+
+      a[2 * i + 1] = [0.0, 0.0, 0.0, x, y, 1.0, -x * yp, -y * yp, 0.0];
+      b[2 * i + 1] = yp;
+   }
+
+   let h = match gauss_solve(a, b) {
+      Some(h) => h,
+      None => return None,
+   };
+
+   Some([h[0], h[1], h[2], h[3], h[4], h[5], h[6], h[7], 1.0])
+}
+
+fn gauss_solve(mut a: [[f64; 9]; 8], mut b: [f64; 8]) -> Option<[f64; 8]> {
+   for col in 0..8 {
+      let mut pivot = col;
+      for row in col + 1..8 {
+         if a[row][col].abs() > a[pivot][col].abs() {
+            pivot = row;
+         }
+      }
+
+      a.swap(col, pivot);
+      b.swap(col, pivot);
+
+      let diag = a[col][col];
+      if diag.abs() < SINGULAR_EPSILON {
+         return None;
+      }
+
+      for row in col + 1..8 {
+         let factor = a[row][col] / diag;
+
+         for k in col..8 {
+            a[row][k] -= factor * a[col][k];
+         }
+         b[row] -= factor * b[col];
+      }
+   }
+
+   let mut x = [0f64; 8];
+
+   for row in (0..8).rev() {
+      if a[row][row].abs() < SINGULAR_EPSILON {
+         return None;
+      }
+
+      let mut sum = b[row];
+      for k in row + 1..8 {
+         sum -= a[row][k] * x[k];
+      }
+      x[row] = sum / a[row][row];
+   }
+
+   Some(x)
+}
@@ -183,6 +183,89 @@ impl RasterizerIntersection for Rasterizer {
                vert_ref.end = vert_prev_end;
                vert_ref.first_px = start;
             },
+            EdgeType::ATR | EdgeType::ATL | EdgeType::ABR | EdgeType::ABL => {
+
+               let segment_index = edge.segment;
+
+               let ref mut vert_ref = self.vert_intersections_ref[segment_index];
+               if vert_ref.start != usize::MAX {
+                  continue;
+               }
+
+               let ref mut hori_ref = self.hori_intersections_ref[segment_index];
+
+               let ref segment = scene.segments[segment_index];
+               let ref p1 = scene.points[segment.p1];
+               let ref p2 = scene.points[segment.p2];
+
+               hori_ref.start = hori_prev_end;
+               vert_ref.start = vert_prev_end;
+
+               let ref ellipse = scene.ellipses[edge.ellipse];
+               let ref center = scene.points[ellipse.center];
+               let rx = ellipse.rx;
+               let ry = ellipse.ry;
+
+               let start = 1 + p1.y / self.div_per_pixel;
+               let end = 1 + (p2.y - 1) / self.div_per_pixel;
+
+               debug_assert!(p1.y <= p2.y);
+
+               for y_px in start..end {
+                  let y = y_px * self.div_per_pixel;
+                  let dy = y - center.y;
+
+                  debug_assert!(ry > dy.abs());
+
+                  let dx = rx * (ry * ry - dy * dy).sqrt() / ry;
+
+                  debug_assert!(dx > 0);
+
+                  let x = match edge.edge_type {
+                     EdgeType::ATR | EdgeType::ATL => center.x - dx,
+                     _ => center.x + dx
+                  };
+
+                  self.hori_intersections[hori_prev_end] = x;
+                  hori_prev_end += 1;
+
+               }
+
+               hori_ref.end = hori_prev_end;
+               hori_ref.first_px = start;
+
+               let (x1, x2) = match edge.edge_type {
+                  EdgeType::ATR | EdgeType::ABL => (p1.x, p2.x),
+                  _ => (p2.x, p1.x),
+               };
+
+               debug_assert!(x1 <= x2);
+
+               let start = 1 + x1 / self.div_per_pixel;
+               let end = 1 + (x2 - 1) / self.div_per_pixel;
+
+               for x_px in start..end {
+                  let x = x_px * self.div_per_pixel;
+                  let dx = center.x - x;
+
+                  debug_assert!(rx > dx.abs());
+
+                  let dy = ry * (rx * rx - dx * dx).sqrt() / rx;
+
+                  debug_assert!(dy > 0);
+
+                  let y = match edge.edge_type {
+                     EdgeType::ATR | EdgeType::ABR => center.y + dy,
+                     _ => center.y - dy
+                  };
+
+                  self.vert_intersections[vert_prev_end] = y;
+                  vert_prev_end += 1;
+               }
+
+               vert_ref.end = vert_prev_end;
+               vert_ref.first_px = start;
+            },
             _ => {}
          }
       }
@@ -264,6 +347,106 @@ impl RasterizerIntersection for Rasterizer {
 }
 
 
+pub trait RasterizerCoverage {
+   fn accumulate_coverage(&self, scene: &Scene, y_px: i64, coverage: &mut [u8]);
+}
+
+
+impl RasterizerCoverage for Rasterizer {
+   fn accumulate_coverage(&self, scene: &Scene, y_px: i64, coverage: &mut [u8]) {
+      for alpha in coverage.iter_mut() {
+         *alpha = 0;
+      }
+
+      let mut crossings: Vec<(i64, i32)> = Vec::new();
+
+      for edge in &scene.edges {
+         let winding = match edge.edge_type {
+            EdgeType::LTR | EdgeType::CTR | EdgeType::ATR |
+            EdgeType::LBR | EdgeType::CBR | EdgeType::ABR |
+            EdgeType::LVT => 1,
+            EdgeType::LTL | EdgeType::CTL | EdgeType::ATL |
+            EdgeType::LBL | EdgeType::CBL | EdgeType::ABL |
+            EdgeType::LVB => -1,
+            _ => continue,
+         };
+
+         match edge.edge_type {
+            EdgeType::LVT | EdgeType::LVB => {
+               let y = y_px * self.div_per_pixel;
+               if y < edge.p1.y || y >= edge.p2.y {
+                  continue;
+               }
+
+               crossings.push((edge.p1.x, winding));
+            },
+            _ => {
+               let ref h_ref = self.hori_intersections_ref[edge.segment];
+               if y_px < h_ref.first_px || y_px >= h_ref.first_px + (h_ref.end - h_ref.start) as i64 {
+                  continue;
+               }
+
+               crossings.push((self.h_intersection(edge, y_px), winding));
+            },
+         }
+      }
+
+      crossings.sort_by_key(|&(x, _)| x);
+
+      let mut winding_sum = 0;
+      let mut span_start = 0;
+
+      for (x, winding) in crossings {
+         if winding_sum != 0 {
+            add_coverage_span(coverage, span_start, x, self.div_per_pixel);
+         }
+
+         winding_sum += winding;
+         span_start = x;
+      }
+   }
+}
+
+
+fn add_coverage_span(coverage: &mut [u8], from_x: i64, to_x: i64, div_per_pixel: i64) {
+   if coverage.is_empty() {
+      return;
+   }
+
+   let canvas_max = coverage.len() as i64 * div_per_pixel;
+
+   let from_x = max(0, min(from_x, canvas_max));
+   let to_x = max(0, min(to_x, canvas_max));
+
+   let start_px = min((from_x / div_per_pixel) as usize, coverage.len() - 1);
+   let end_px = min((to_x / div_per_pixel) as usize, coverage.len() - 1);
+
+   if start_px == end_px {
+      add_alpha(coverage, start_px, to_x - from_x, div_per_pixel);
+      return;
+   }
+
+   let enter = (start_px + 1) as i64 * div_per_pixel - from_x;
+   add_alpha(coverage, start_px, enter, div_per_pixel);
+
+   for px in start_px + 1..end_px {
+      add_alpha(coverage, px, div_per_pixel, div_per_pixel);
+   }
+
+   let exit = to_x - end_px as i64 * div_per_pixel;
+   add_alpha(coverage, end_px, exit, div_per_pixel);
+}
+
+fn add_alpha(coverage: &mut [u8], px: usize, covered: i64, div_per_pixel: i64) {
+   if px >= coverage.len() {
+      return;
+   }
+
+   let added = (covered * 255 / div_per_pixel) as i32;
+   coverage[px] = (coverage[px] as i32 + added).min(255).max(0) as u8;
+}
+
+
 fn h_multi_intersect_fast(p1: &Point, p2: &Point, step_y: i64, mut vec_start: usize, inters: &mut Vec<i64>) -> (usize, i64) {
    let (p1, p2) = if p1.y > p2.y {
       (p2, p1)
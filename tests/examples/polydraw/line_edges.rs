@@ -0,0 +1,40 @@
+use geom::point::Point;
+
+use super::scene::{Scene, Segment};
+use super::edge::{Edge, EdgeType};
+
+
+pub fn classify_line_edge_type(p1: Point, p2: Point) -> EdgeType {
+   if p1.y == p2.y {
+      if p2.x >= p1.x { EdgeType::LHR } else { EdgeType::LHL }
+   } else if p1.x == p2.x {
+      if p2.y >= p1.y { EdgeType::LVT } else { EdgeType::LVB }
+   } else {
+      match (p2.y >= p1.y, p2.x >= p1.x) {
+         (true, true) => EdgeType::LTR,
+         (true, false) => EdgeType::LTL,
+         (false, true) => EdgeType::LBR,
+         (false, false) => EdgeType::LBL,
+      }
+   }
+}
+
+pub fn push_line_edge(scene: &mut Scene, p1: Point, p2: Point) {
+   let p1_index = scene.points.len();
+   scene.points.push(p1);
+
+   let p2_index = scene.points.len();
+   scene.points.push(p2);
+
+   let segment_index = scene.segments.len();
+   scene.segments.push(Segment { p1: p1_index, p2: p2_index });
+
+   scene.edges.push(Edge {
+      edge_type: classify_line_edge_type(p1, p2),
+      segment: segment_index,
+      circle: 0,
+      ellipse: 0,
+      p1: p1,
+      p2: p2,
+   });
+}
@@ -0,0 +1,186 @@
+use geom::point::Point;
+use num::NumberOps;
+
+use super::bezier::{CubicBezier, flatten_cubic};
+
+
+const NORMAL_SCALE: i64 = 1 << 16;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineCap {
+   Butt,
+   Round,
+   Square,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineJoin {
+   Round,
+}
+
+
+pub fn stroke_to_fill(
+   points: &[Point], width: i64, closed: bool, cap: LineCap, join: LineJoin,
+   flattening_tolerance: i64,
+) -> Vec<Point> {
+
+   let half = width / 2;
+
+   let mut left = Vec::new();
+   let mut right = Vec::new();
+
+   let n = points.len();
+   let segment_count = if closed { n } else { n - 1 };
+
+   for i in 0..segment_count {
+      let a = points[i];
+      let b = points[(i + 1) % n];
+
+      let (nx, ny) = normal(a, b);
+
+      left.push(Point::new(a.x + nx * half / NORMAL_SCALE, a.y + ny * half / NORMAL_SCALE));
+      left.push(Point::new(b.x + nx * half / NORMAL_SCALE, b.y + ny * half / NORMAL_SCALE));
+
+      right.push(Point::new(a.x - nx * half / NORMAL_SCALE, a.y - ny * half / NORMAL_SCALE));
+      right.push(Point::new(b.x - nx * half / NORMAL_SCALE, b.y - ny * half / NORMAL_SCALE));
+
+      if join == LineJoin::Round && (closed || i + 1 < segment_count) {
+         let next_b = points[(i + 2) % n];
+         let (nx2, ny2) = normal(b, next_b);
+
+         add_round_join(&mut left, b, nx, ny, nx2, ny2, half, flattening_tolerance);
+         add_round_join(&mut right, b, -nx, -ny, -nx2, -ny2, half, flattening_tolerance);
+      }
+   }
+
+   let mut outline = left;
+
+   if closed {
+      right.reverse();
+      outline.extend(right);
+   } else {
+      add_cap(&mut outline, points[n - 1], points[n - 2], half, cap, flattening_tolerance);
+      right.reverse();
+      outline.extend(right);
+      add_cap(&mut outline, points[0], points[1], half, cap, flattening_tolerance);
+   }
+
+   outline
+}
+
+
+fn add_round_join(
+   out: &mut Vec<Point>, center: Point, nx1: i64, ny1: i64, nx2: i64, ny2: i64,
+   half: i64, flattening_tolerance: i64,
+) {
+   let start = Point::new(
+      center.x + nx1 * half / NORMAL_SCALE, center.y + ny1 * half / NORMAL_SCALE
+   );
+   let end = Point::new(
+      center.x + nx2 * half / NORMAL_SCALE, center.y + ny2 * half / NORMAL_SCALE
+   );
+
+   let n1x = nx1 as f64 / NORMAL_SCALE as f64;
+   let n1y = ny1 as f64 / NORMAL_SCALE as f64;
+   let n2x = nx2 as f64 / NORMAL_SCALE as f64;
+   let n2y = ny2 as f64 / NORMAL_SCALE as f64;
+
+   let cos_delta = n1x * n2x + n1y * n2y;
+   let sin_delta = n1x * n2y - n1y * n2x;
+
+   let delta = sin_delta.atan2(cos_delta);
+   if delta.abs() < 1e-9 {
+      out.push(end);
+      return;
+   }
+
+   let h = half as f64 * 4.0 / 3.0 * (delta / 4.0).tan();
+
+   let t1x = -n1y;
+   let t1y = n1x;
+   let t2x = -n2y;
+   let t2y = n2x;
+
+   let c1 = Point::new(
+      start.x + (t1x * h).round() as i64, start.y + (t1y * h).round() as i64
+   );
+   let c2 = Point::new(
+      end.x - (t2x * h).round() as i64, end.y - (t2y * h).round() as i64
+   );
+
+   let curve = CubicBezier { p0: start, p1: c1, p2: c2, p3: end };
+
+   let mut flattened = Vec::new();
+   flatten_cubic(&curve, flattening_tolerance, &mut flattened);
+
+   out.extend(flattened.into_iter().skip(1));
+}
+
+
+fn add_cap(
+   out: &mut Vec<Point>, end: Point, from: Point, half: i64, cap: LineCap,
+   flattening_tolerance: i64,
+) {
+   let (nx, ny) = normal(from, end);
+
+   match cap {
+      LineCap::Butt => {},
+      LineCap::Square => {
+         let (dx, dy) = direction(from, end);
+         let ox = nx * half / NORMAL_SCALE;
+         let oy = ny * half / NORMAL_SCALE;
+         let fx = dx * half / NORMAL_SCALE;
+         let fy = dy * half / NORMAL_SCALE;
+         out.push(Point::new(end.x + ox + fx, end.y + oy + fy));
+         out.push(Point::new(end.x - ox + fx, end.y - oy + fy));
+      },
+      LineCap::Round => {
+         let (dx, dy) = direction(from, end);
+         add_round_join(out, end, nx, ny, dx, dy, half, flattening_tolerance);
+         add_round_join(out, end, dx, dy, -nx, -ny, half, flattening_tolerance);
+      },
+   }
+}
+
+
+fn direction(a: Point, b: Point) -> (i64, i64) {
+   let dx = b.x - a.x;
+   let dy = b.y - a.y;
+   let len = (dx * dx + dy * dy).sqrt();
+
+   if len == 0 {
+      (0, 0)
+   } else {
+      (dx * NORMAL_SCALE / len, dy * NORMAL_SCALE / len)
+   }
+}
+
+fn normal(a: Point, b: Point) -> (i64, i64) {
+   let (dx, dy) = direction(a, b);
+   (-dy, dx)
+}
+
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn round_join_stays_close_to_the_circular_arc() {
+      let center = Point::new(0, 0);
+      let half = 1000;
+
+      let mut out = vec![Point::new(half, 0)];
+      add_round_join(&mut out, center, NORMAL_SCALE, 0, 0, NORMAL_SCALE, half, 1);
+
+      let radius_sq = half * half;
+
+      for p in &out {
+         let dist_sq = p.x * p.x + p.y * p.y;
+         let error = (dist_sq - radius_sq).abs();
+
+         assert!(error * 100 <= radius_sq * 5, "point ({}, {}) strayed off the arc radius", p.x, p.y);
+      }
+   }
+}